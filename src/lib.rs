@@ -1,6 +1,8 @@
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use bytes::Bytes;
 use http::Uri;
@@ -9,15 +11,66 @@ use http_body_util::BodyExt;
 use tower_service::Service;
 use tracing::{debug, warn};
 
+/// Predicate deciding whether a request header should be forwarded to the
+/// WASI host, called with the lowercase header name.
+type HeaderFilter = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
 /// Component-side gRPC endpoint that uses wasi:http/outgoing-handler
 #[derive(Clone)]
 pub struct GrpcEndpoint {
     endpoint: Uri,
+    connect_timeout: Option<Duration>,
+    first_byte_timeout: Option<Duration>,
+    scheme: Option<wasmcloud_component::wasi::http::types::Scheme>,
+    header_filter: Option<HeaderFilter>,
 }
 
 impl GrpcEndpoint {
     pub fn new(endpoint: Uri) -> Self {
-        Self { endpoint }
+        Self {
+            endpoint,
+            connect_timeout: None,
+            first_byte_timeout: None,
+            scheme: None,
+            header_filter: None,
+        }
+    }
+
+    /// Default connect timeout used for every request. A client that also
+    /// sends `grpc-timeout` still takes precedence for the first-byte and
+    /// between-bytes deadlines below, since connection setup isn't part of
+    /// the gRPC deadline it encodes.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Default first-byte timeout used when the request carries no
+    /// `grpc-timeout` header.
+    pub fn with_first_byte_timeout(mut self, timeout: Duration) -> Self {
+        self.first_byte_timeout = Some(timeout);
+        self
+    }
+
+    /// Forces the scheme sent to the WASI host, independent of the inbound
+    /// request's URI. WASI HTTP treats the scheme as out-of-band information
+    /// the caller must supply, so a component that wants TLS even when the
+    /// incoming URI says otherwise should set this explicitly.
+    pub fn with_scheme(mut self, scheme: wasmcloud_component::wasi::http::types::Scheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Restricts which request headers get forwarded to the WASI host.
+    /// Called with the lowercase header name; return `false` to drop it
+    /// before it's even attempted against `Fields::append`. Headers the host
+    /// itself forbids are dropped regardless of this predicate.
+    pub fn with_header_filter(
+        mut self,
+        filter: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.header_filter = Some(Arc::new(filter));
+        self
     }
 }
 
@@ -38,14 +91,40 @@ where
         use wasmcloud_component::wasi::http::{outgoing_handler, types};
 
         let endpoint_parts = self.endpoint.clone().into_parts();
+        let configured_scheme = self.scheme.clone();
+        let connect_timeout = self.connect_timeout;
+        let first_byte_timeout = self.first_byte_timeout;
+        let header_filter = self.header_filter.clone();
         let (mut parts, body) = req.into_parts();
+
+        // Prefer the endpoint's configured authority (needed for correct SNI)
+        // over whatever the inbound request happened to carry, but fall back
+        // to the request's own URI authority or `Host` header so a caller
+        // that only set those still resolves.
+        let authority = endpoint_parts
+            .authority
+            .clone()
+            .or_else(|| parts.uri.authority().cloned())
+            .or_else(|| header_authority(&parts.headers));
+
         let mut uri_parts = std::mem::take(&mut parts.uri).into_parts();
-        uri_parts.authority = endpoint_parts.authority;
-        uri_parts.scheme = endpoint_parts.scheme;
+        uri_parts.scheme = endpoint_parts.scheme.or(uri_parts.scheme);
+        // `Uri::from_parts` rejects an authority without a scheme, so a
+        // relative URI stays relative; the WASI request gets `authority` set
+        // explicitly below either way.
+        if uri_parts.scheme.is_some() {
+            uri_parts.authority = authority.clone();
+        }
 
         let final_uri = Uri::from_parts(uri_parts);
 
         Box::pin(async move {
+            let authority = authority.ok_or_else(|| {
+                "request has no resolvable authority: configure GrpcEndpoint with an authority \
+                 or set a Host header"
+                    .to_string()
+            })?;
+
             let final_uri =
                 final_uri.map_err(|e| format!("failed to construct request URI: {e}"))?;
             parts.uri = final_uri;
@@ -56,12 +135,6 @@ where
                 "sending gRPC request via WASI"
             );
 
-            let body_bytes = body
-                .collect()
-                .await
-                .map_err(|e| format!("failed to collect request body: {e}"))?
-                .to_bytes();
-
             let headers = types::Fields::new();
 
             // Skip HTTP/2 pseudo-headers and HTTP/1.1 connection-specific headers
@@ -86,10 +159,21 @@ where
                     _ => {}
                 }
 
+                if let Some(filter) = &header_filter {
+                    if !filter(name_str) {
+                        debug!(header = name_str, "skipping header excluded by configured filter");
+                        continue;
+                    }
+                }
+
                 let value_bytes = value.as_bytes().to_vec();
-                headers
-                    .append(&name_str.to_string(), &value_bytes)
-                    .map_err(|e| format!("failed to append header {name_str}: {e:?}"))?;
+                // The WASI host applies its own forbidden/immutable-header
+                // filtering here, rejecting names we can't predict in
+                // advance. Drop those rather than failing the whole RPC, the
+                // same as the hard-coded skips above.
+                if let Err(e) = headers.append(&name_str.to_string(), &value_bytes) {
+                    warn!(header = name_str, error = ?e, "WASI host rejected header; dropping");
+                }
             }
 
             let wasi_request = types::OutgoingRequest::new(headers);
@@ -99,18 +183,19 @@ where
                 .set_method(&method)
                 .map_err(|e| format!("failed to set HTTP method: {e:?}"))?;
 
-            if let Some(scheme) = parts.uri.scheme() {
-                let wasi_scheme = convert_scheme(scheme);
+            // A configured scheme always wins: WASI HTTP treats the scheme as
+            // out-of-band information, so the inbound URI's scheme can't be
+            // trusted to force TLS on the caller's behalf.
+            let wasi_scheme = configured_scheme.or_else(|| parts.uri.scheme().map(convert_scheme));
+            if let Some(wasi_scheme) = &wasi_scheme {
                 wasi_request
-                    .set_scheme(Some(&wasi_scheme))
+                    .set_scheme(Some(wasi_scheme))
                     .map_err(|e| format!("failed to set URI scheme: {e:?}"))?;
             }
 
-            if let Some(authority) = parts.uri.authority() {
-                wasi_request
-                    .set_authority(Some(authority.as_str()))
-                    .map_err(|e| format!("failed to set URI authority: {e:?}"))?;
-            }
+            wasi_request
+                .set_authority(Some(authority.as_str()))
+                .map_err(|e| format!("failed to set URI authority: {e:?}"))?;
 
             if let Some(path_and_query) = parts.uri.path_and_query() {
                 wasi_request
@@ -126,19 +211,93 @@ where
                 .write()
                 .map_err(|e| format!("failed to get body output stream: {e:?}"))?;
 
-            output_stream
-                .blocking_write_and_flush(&body_bytes)
-                .map_err(|e| format!("failed to write request body: {e:?}"))?;
+            // Pump frames one at a time instead of collecting the whole body
+            // into one buffer first. The request is only handed to the host
+            // (`outgoing_handler::handle` below) once the body has ended, so a
+            // request body that stays open until the server responds, as in
+            // client- or bidi-streaming RPCs, is never sent.
+            let mut body = std::pin::pin!(body);
+            let mut trailer_frame = None;
+
+            while let Some(frame) = body.frame().await {
+                let frame = frame.map_err(|e| format!("failed to read request body frame: {e}"))?;
+
+                let frame = match frame.into_data() {
+                    Ok(data) => {
+                        write_body_chunk(&output_stream, &data)?;
+                        output_stream
+                            .blocking_flush()
+                            .map_err(|e| format!("failed to flush request body: {e:?}"))?;
+                        continue;
+                    }
+                    Err(frame) => frame,
+                };
+
+                if let Ok(trailers) = frame.into_trailers() {
+                    trailer_frame = Some(trailers);
+                }
+                break;
+            }
 
             drop(output_stream);
-            types::OutgoingBody::finish(outgoing_body, None)
+
+            let wasi_trailers = match trailer_frame {
+                Some(trailers) => {
+                    let fields = types::Fields::new();
+                    for (name, value) in trailers.iter() {
+                        fields
+                            .append(&name.to_string(), &value.as_bytes().to_vec())
+                            .map_err(|e| format!("failed to append trailer {name}: {e:?}"))?;
+                    }
+                    Some(fields)
+                }
+                None => None,
+            };
+
+            types::OutgoingBody::finish(outgoing_body, wasi_trailers)
                 .map_err(|e| format!("failed to finish request body: {e:?}"))?;
 
             let request_options = types::RequestOptions::new();
+
+            let grpc_timeout_nanos = parts
+                .headers
+                .get("grpc-timeout")
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_grpc_timeout);
+
+            let first_byte_nanos =
+                grpc_timeout_nanos.or_else(|| first_byte_timeout.map(duration_to_nanos));
+            if let Some(nanos) = first_byte_nanos {
+                apply_timeout(
+                    "first-byte",
+                    request_options.set_first_byte_timeout(Some(nanos)),
+                );
+            }
+
+            // `grpc-timeout` bounds the whole call, so it also governs the
+            // gap between subsequent response chunks.
+            if let Some(nanos) = grpc_timeout_nanos {
+                apply_timeout(
+                    "between-bytes",
+                    request_options.set_between_bytes_timeout(Some(nanos)),
+                );
+            }
+
+            if let Some(timeout) = connect_timeout {
+                apply_timeout(
+                    "connect",
+                    request_options.set_connect_timeout(Some(duration_to_nanos(timeout))),
+                );
+            }
+
             let future_response = outgoing_handler::handle(wasi_request, Some(request_options))
                 .map_err(|e| format!("failed to initiate HTTP request: {e:?}"))?;
 
-            future_response.subscribe().block();
+            // Poll cooperatively rather than `block()`ing the whole component,
+            // so other streams/tasks get a chance to make progress while we
+            // wait for headers.
+            let response_pollable = future_response.subscribe();
+            wait_pollable(&response_pollable).await;
 
             let incoming_response = match future_response.get() {
                 Some(Ok(Ok(resp))) => resp,
@@ -167,10 +326,13 @@ where
             let input_stream = response_body
                 .stream()
                 .map_err(|e| format!("failed to get response stream: {e:?}"))?;
+            let pollable = input_stream.subscribe();
 
             let body = WasiResponseBody {
-                input_stream,
-                _response_body: response_body,
+                pollable,
+                input_stream: Some(input_stream),
+                state: ResponseBodyState::Reading,
+                response_body: Some(response_body),
             };
             response_builder
                 .body(body)
@@ -179,10 +341,55 @@ where
     }
 }
 
+/// Drives `WasiResponseBody::poll_frame` through data frames and then, once
+/// the input stream is exhausted, through the trailing `FutureTrailers`.
+enum ResponseBodyState {
+    /// Reading data frames off `input_stream`.
+    Reading,
+    /// `input_stream` is exhausted; waiting on the trailers future returned
+    /// by `IncomingBody::finish`.
+    Finishing(wasmcloud_component::wasi::http::types::FutureTrailers),
+    /// Trailers have been emitted (or there were none); body is complete.
+    Done,
+}
+
 /// Response body that streams from WASI HTTP input stream
 pub struct WasiResponseBody {
-    input_stream: wasmcloud_component::wasi::io::streams::InputStream,
-    _response_body: wasmcloud_component::wasi::http::types::IncomingBody,
+    // Declared (and therefore dropped) before `input_stream` and `state`:
+    // the host may trap if a pollable outlives the resource it was
+    // subscribed from, and Rust drops struct fields in declaration order.
+    // This pollable is `input_stream`'s while `state` is `Reading`, swapped
+    // for the `FutureTrailers`' own pollable once we move to `Finishing`.
+    // Each swap goes through an already-ready clock pollable so the old one
+    // is dropped before the resource it was subscribed from.
+    pollable: wasmcloud_component::wasi::io::poll::Pollable,
+    // `Option` because `IncomingBody::finish` traps if its child
+    // `input-stream` is still alive, so this is taken and dropped before
+    // `finish` is called.
+    input_stream: Option<wasmcloud_component::wasi::io::streams::InputStream>,
+    state: ResponseBodyState,
+    // `IncomingBody::finish` consumes its argument, so this is taken once the
+    // input stream is drained.
+    response_body: Option<wasmcloud_component::wasi::http::types::IncomingBody>,
+}
+
+/// Awaits a `Pollable` without blocking the component: on each poll we check
+/// readiness non-blockingly and, if not ready yet, re-wake ourselves so the
+/// executor can drive other futures before we check again.
+async fn wait_pollable(pollable: &wasmcloud_component::wasi::io::poll::Pollable) {
+    std::future::poll_fn(|cx| {
+        if pollable.ready() {
+            Poll::Ready(())
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+fn io_error(message: String) -> Box<dyn std::error::Error + Send + Sync> {
+    Box::new(std::io::Error::new(std::io::ErrorKind::Other, message))
 }
 
 impl HttpBody for WasiResponseBody {
@@ -191,21 +398,165 @@ impl HttpBody for WasiResponseBody {
 
     fn poll_frame(
         self: Pin<&mut Self>,
-        _cx: &mut Context<'_>,
+        cx: &mut Context<'_>,
     ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
-        match self.input_stream.blocking_read(8192) {
-            Ok(chunk) if chunk.is_empty() => Poll::Ready(None),
-            Ok(chunk) => Poll::Ready(Some(Ok(http_body::Frame::data(Bytes::from(chunk))))),
-            Err(wasmcloud_component::wasi::io::streams::StreamError::Closed) => Poll::Ready(None),
-            Err(e) => {
-                warn!(error = ?e, "failed to read from response stream");
-                Poll::Ready(Some(Err(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("stream read error: {e:?}"),
-                ))
-                    as Box<dyn std::error::Error + Send + Sync>)))
+        use wasmcloud_component::wasi::clocks::monotonic_clock;
+        use wasmcloud_component::wasi::http::types;
+        use wasmcloud_component::wasi::io::streams::StreamError;
+
+        let this = self.get_mut();
+
+        loop {
+            if !this.pollable.ready() {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
             }
+
+            match &this.state {
+                ResponseBodyState::Reading => match this
+                    .input_stream
+                    .as_ref()
+                    .expect("input stream already finished")
+                    .read(8192)
+                {
+                    Ok(chunk) if chunk.is_empty() => {
+                        // `IncomingBody::finish` traps if its child
+                        // `input-stream` is still alive, so drop it first.
+                        this.pollable = monotonic_clock::subscribe_duration(0);
+                        drop(this.input_stream.take());
+                        let response_body = this
+                            .response_body
+                            .take()
+                            .expect("response body already finished");
+                        let future_trailers = types::IncomingBody::finish(response_body);
+                        this.pollable = future_trailers.subscribe();
+                        this.state = ResponseBodyState::Finishing(future_trailers);
+                    }
+                    Ok(chunk) => {
+                        return Poll::Ready(Some(Ok(http_body::Frame::data(Bytes::from(chunk)))))
+                    }
+                    Err(StreamError::Closed) => {
+                        this.pollable = monotonic_clock::subscribe_duration(0);
+                        drop(this.input_stream.take());
+                        let response_body = this
+                            .response_body
+                            .take()
+                            .expect("response body already finished");
+                        let future_trailers = types::IncomingBody::finish(response_body);
+                        this.pollable = future_trailers.subscribe();
+                        this.state = ResponseBodyState::Finishing(future_trailers);
+                    }
+                    Err(e) => {
+                        warn!(error = ?e, "failed to read from response stream");
+                        return Poll::Ready(Some(Err(io_error(format!(
+                            "stream read error: {e:?}"
+                        )))));
+                    }
+                },
+                ResponseBodyState::Finishing(_) => {
+                    let future_trailers = match std::mem::replace(&mut this.state, ResponseBodyState::Done)
+                    {
+                        ResponseBodyState::Finishing(future_trailers) => future_trailers,
+                        _ => unreachable!(),
+                    };
+                    let result = future_trailers.get();
+                    this.pollable = monotonic_clock::subscribe_duration(0);
+
+                    return match result {
+                        Some(Ok(Ok(Some(fields)))) => {
+                            let mut map = http::HeaderMap::new();
+                            for (name, value) in fields.entries() {
+                                let Ok(header_name) = http::HeaderName::from_bytes(name.as_bytes())
+                                else {
+                                    warn!(header = name, "skipping invalid trailer name");
+                                    continue;
+                                };
+                                let Ok(header_value) = http::HeaderValue::from_bytes(&value) else {
+                                    warn!(header = name, "skipping invalid trailer value");
+                                    continue;
+                                };
+                                map.append(header_name, header_value);
+                            }
+                            Poll::Ready(Some(Ok(http_body::Frame::trailers(map))))
+                        }
+                        Some(Ok(Ok(None))) | None => Poll::Ready(None),
+                        Some(Ok(Err(e))) => {
+                            Poll::Ready(Some(Err(io_error(format!("trailers error: {e:?}")))))
+                        }
+                        Some(Err(_)) => {
+                            Poll::Ready(Some(Err(io_error("trailers future error".to_string()))))
+                        }
+                    };
+                }
+                ResponseBodyState::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Writes `data` to `output_stream` in chunks bounded by `check_write`,
+/// blocking on the stream's pollable whenever the guest-side buffer is full.
+fn write_body_chunk(
+    output_stream: &wasmcloud_component::wasi::io::streams::OutputStream,
+    mut data: &[u8],
+) -> Result<(), String> {
+    while !data.is_empty() {
+        let permit = output_stream
+            .check_write()
+            .map_err(|e| format!("failed to check body stream writability: {e:?}"))?;
+
+        if permit == 0 {
+            output_stream.subscribe().block();
+            continue;
         }
+
+        let take = data.len().min(permit as usize);
+        let (chunk, rest) = data.split_at(take);
+        output_stream
+            .write(chunk)
+            .map_err(|e| format!("failed to write request body: {e:?}"))?;
+        data = rest;
+    }
+
+    Ok(())
+}
+
+/// Parses a `grpc-timeout` header value (an ASCII integer followed by a unit
+/// character: `H`/`M`/`S`/`m`/`u`/`n`) into a total nanosecond count,
+/// saturating on overflow rather than rejecting oversized deadlines.
+fn parse_grpc_timeout(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let split_at = value.len().checked_sub(1)?;
+    let (digits, unit) = value.split_at(split_at);
+    let amount: u64 = match digits.parse::<u64>() {
+        Ok(amount) => amount,
+        Err(e) if *e.kind() == std::num::IntErrorKind::PosOverflow => u64::MAX,
+        Err(_) => return None,
+    };
+
+    let nanos_per_unit: u64 = match unit {
+        "H" => 3_600_000_000_000,
+        "M" => 60_000_000_000,
+        "S" => 1_000_000_000,
+        "m" => 1_000_000,
+        "u" => 1_000,
+        "n" => 1,
+        _ => return None,
+    };
+
+    Some(amount.saturating_mul(nanos_per_unit))
+}
+
+fn duration_to_nanos(duration: Duration) -> u64 {
+    duration.as_nanos().min(u64::MAX as u128) as u64
+}
+
+/// Logs and swallows `RequestOptions::set_*_timeout` rejections: hosts that
+/// don't support a given deadline return an error rather than ignore it, and
+/// that shouldn't fail a request that would otherwise succeed.
+fn apply_timeout(label: &str, result: Result<(), ()>) {
+    if result.is_err() {
+        debug!(timeout = label, "WASI host does not support this timeout option");
     }
 }
 
@@ -240,3 +591,53 @@ fn convert_scheme(
         WasiScheme::Other(scheme.as_str().to_string())
     }
 }
+
+/// Falls back to the request's `Host` header when neither the configured
+/// endpoint nor the request URI itself carries an authority.
+fn header_authority(headers: &http::HeaderMap) -> Option<http::uri::Authority> {
+    headers
+        .get(http::header::HOST)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_grpc_timeout;
+
+    #[test]
+    fn parses_each_unit() {
+        assert_eq!(parse_grpc_timeout("1H"), Some(3_600_000_000_000));
+        assert_eq!(parse_grpc_timeout("1M"), Some(60_000_000_000));
+        assert_eq!(parse_grpc_timeout("1S"), Some(1_000_000_000));
+        assert_eq!(parse_grpc_timeout("1m"), Some(1_000_000));
+        assert_eq!(parse_grpc_timeout("1u"), Some(1_000));
+        assert_eq!(parse_grpc_timeout("1n"), Some(1));
+        assert_eq!(parse_grpc_timeout("10S"), Some(10_000_000_000));
+    }
+
+    #[test]
+    fn saturates_on_overflow() {
+        assert_eq!(parse_grpc_timeout("99999999999999H"), Some(u64::MAX));
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(parse_grpc_timeout(""), None);
+    }
+
+    #[test]
+    fn rejects_unit_only_input() {
+        assert_eq!(parse_grpc_timeout("S"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        assert_eq!(parse_grpc_timeout("5X"), None);
+    }
+
+    #[test]
+    fn rejects_non_numeric_amount() {
+        assert_eq!(parse_grpc_timeout("abcS"), None);
+    }
+}